@@ -0,0 +1,323 @@
+//! Normalize path middleware
+
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use salvo_core::http::response::Body;
+use salvo_core::http::uri::{PathAndQuery, Uri};
+use salvo_core::prelude::*;
+
+use crate::trailing_slash::replace_uri_path;
+
+/// Trailing slash policy applied by [`NormalizePath`] after duplicate slashes have been merged.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum TrailingSlash {
+    /// Strip a trailing slash, except for the root path `/`.
+    Trim,
+    /// Only merge duplicate slashes, leaving the trailing slash untouched.
+    MergeOnly,
+    /// Ensure the path ends with exactly one trailing slash.
+    Always,
+}
+
+/// `NormalizePath` collapses duplicate `/` characters and applies a [`TrailingSlash`] policy.
+///
+/// By default the normalized `Uri` is written back onto the request and routing falls through
+/// to the matching route. Set [`NormalizePath::use_redirects`] to instead send the client a
+/// redirect to the normalized path.
+pub struct NormalizePath {
+    /// Policy used for the trailing slash after duplicate slashes are merged.
+    pub trailing_slash: TrailingSlash,
+    /// When `Some`, redirect the client to the normalized path using this status code instead
+    /// of rewriting the request in place.
+    pub use_redirects: Option<StatusCode>,
+    /// When `true`, resolve `.` and `..` segments (see [`clean_path`]) before merging slashes.
+    pub clean_path: bool,
+}
+
+impl NormalizePath {
+    /// Create a new `NormalizePath` with the given [`TrailingSlash`] policy.
+    ///
+    /// Rewrites the request in place by default; call [`NormalizePath::with_redirects`] to
+    /// redirect the client instead.
+    #[inline]
+    pub fn new(trailing_slash: TrailingSlash) -> Self {
+        Self {
+            trailing_slash,
+            use_redirects: None,
+            clean_path: false,
+        }
+    }
+
+    /// Redirect the client to the normalized path using `status_code`, instead of rewriting the
+    /// request in place.
+    #[inline]
+    pub fn with_redirects(self, status_code: StatusCode) -> Self {
+        Self {
+            use_redirects: Some(status_code),
+            ..self
+        }
+    }
+
+    /// Resolve `.` and `..` segments (RFC 3986 §5.2.4) before merging duplicate slashes.
+    ///
+    /// Route matching (including wildcard parameter capture, e.g. `<*path>`) runs against the
+    /// original `Uri` before any hoop executes, so this does not retroactively sanitize path
+    /// parameters already captured from it — a `Static` handler reading `req.param("path")`
+    /// still sees the unresolved value. What this does clean up is `req.uri()` itself for
+    /// anything downstream that reads it directly (logging, further hoops, redirects), and it
+    /// blocks traversal against routes matched on the literal path rather than a wildcard
+    /// capture. To also protect a wildcard-captured parameter (e.g. in front of `Static`),
+    /// wrap its goal handler with [`CleanPathParam`] instead.
+    #[inline]
+    pub fn with_clean_path(self, clean_path: bool) -> Self {
+        Self { clean_path, ..self }
+    }
+
+    /// Collapse every run of consecutive `/` into a single `/`, preserving a leading `/`,
+    /// optionally resolve dot segments first, and apply `self.trailing_slash` to the result.
+    /// Returns `None` when the normalized path is identical to `path`.
+    fn normalize(&self, path: &str) -> Option<String> {
+        let dotless = if self.clean_path {
+            Cow::from(clean_path(path))
+        } else {
+            Cow::from(path)
+        };
+
+        let mut merged = String::with_capacity(dotless.len());
+        let mut prev_was_slash = false;
+        for c in dotless.chars() {
+            if c == '/' {
+                if prev_was_slash {
+                    continue;
+                }
+                prev_was_slash = true;
+            } else {
+                prev_was_slash = false;
+            }
+            merged.push(c);
+        }
+
+        let mut normalized = match self.trailing_slash {
+            TrailingSlash::MergeOnly => merged,
+            TrailingSlash::Trim => {
+                if merged.len() > 1 {
+                    merged.trim_end_matches('/').to_owned()
+                } else {
+                    merged
+                }
+            }
+            TrailingSlash::Always => {
+                if merged.ends_with('/') {
+                    merged
+                } else {
+                    merged + "/"
+                }
+            }
+        };
+        if normalized.is_empty() {
+            normalized = "/".into();
+        }
+
+        if normalized == path { None } else { Some(normalized) }
+    }
+}
+
+/// Resolve `.` and `..` segments in `path` per RFC 3986 §5.2.4.
+///
+/// Splits `path` on `/`, drops empty and `.` segments, and pops the previous segment on each
+/// `..` (a `..` above the root is simply dropped, never escaping it). The result is rejoined
+/// with single slashes, keeps a leading `/`, and preserves a trailing `/` if `path` had one.
+pub fn clean_path(path: &str) -> String {
+    let ends_with_slash = path.len() > 1 && path.ends_with('/');
+
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+
+    let mut cleaned = String::from("/");
+    cleaned.push_str(&segments.join("/"));
+    if ends_with_slash && cleaned.len() > 1 {
+        cleaned.push('/');
+    }
+    cleaned
+}
+
+/// Wraps `inner`, resolving `.`/`..` segments (see [`clean_path`]) in the named wildcard path
+/// parameter before calling through.
+///
+/// [`NormalizePath::with_clean_path`] only cleans `req.uri()`, which can't retroactively
+/// sanitize a path parameter already captured from the original `Uri` during route matching.
+/// Wrapping the goal handler of a wildcard route (e.g. `files/<*path>`, how `Static` is usually
+/// mounted) with `CleanPathParam` is what actually protects it: a request for
+/// `/files/../../etc/passwd` reaches `inner` with `path` rewritten to `etc/passwd`.
+pub struct CleanPathParam<H> {
+    name: String,
+    inner: H,
+}
+
+impl<H> CleanPathParam<H> {
+    /// Wrap `inner`, cleaning the wildcard path parameter named `name` before each call.
+    #[inline]
+    pub fn new(name: impl Into<String>, inner: H) -> Self {
+        Self { name: name.into(), inner }
+    }
+}
+
+#[async_trait]
+impl<H: Handler> Handler for CleanPathParam<H> {
+    #[inline]
+    async fn handle(&self, req: &mut Request, depot: &mut Depot, res: &mut Response, ctrl: &mut FlowCtrl) {
+        if let Some(value) = req.params().get(&self.name).cloned() {
+            let cleaned = clean_path(&format!("/{value}"));
+            req.params_mut()
+                .insert(self.name.clone(), cleaned.trim_start_matches('/').to_owned());
+        }
+        self.inner.handle(req, depot, res, ctrl).await;
+    }
+}
+
+/// Wrap `inner`, cleaning the wildcard path parameter named `name` before each call.
+///
+/// Shorthand for [`CleanPathParam::new`].
+#[inline]
+pub fn clean_path_param(name: impl Into<String>, inner: impl Handler) -> CleanPathParam<impl Handler> {
+    CleanPathParam::new(name, inner)
+}
+
+#[async_trait]
+impl Handler for NormalizePath {
+    #[inline]
+    async fn handle(&self, req: &mut Request, _depot: &mut Depot, res: &mut Response, ctrl: &mut FlowCtrl) {
+        let Some(normalized) = self.normalize(req.uri().path()) else {
+            return;
+        };
+        let new_uri = replace_uri_path(req.uri(), &normalized);
+
+        if let Some(status_code) = self.use_redirects {
+            ctrl.skip_rest();
+            res.set_body(Body::None);
+            match Redirect::with_status_code(status_code, new_uri) {
+                Ok(redirect) => {
+                    res.render(redirect);
+                }
+                Err(e) => {
+                    tracing::error!(error = ?e, "redirect failed");
+                }
+            }
+        } else {
+            *req.uri_mut() = new_uri;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo_core::http::StatusCode;
+    use salvo_core::prelude::*;
+    use salvo_core::test::TestClient;
+
+    use super::*;
+
+    #[handler]
+    async fn hello_world() -> &'static str {
+        "Hello World"
+    }
+
+    #[tokio::test]
+    async fn test_normalize_merge_only() {
+        let router =
+            Router::with_hoop(NormalizePath::new(TrailingSlash::MergeOnly)).push(Router::with_path("a/b/c").get(hello_world));
+        let service = Service::new(router);
+        let res = TestClient::get("http://127.0.0.1:7878/a//b///c").send(&service).await;
+        assert_eq!(res.status_code().unwrap(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_normalize_trim() {
+        let router =
+            Router::with_hoop(NormalizePath::new(TrailingSlash::Trim)).push(Router::with_path("a/b").get(hello_world));
+        let service = Service::new(router);
+        let res = TestClient::get("http://127.0.0.1:7878/a//b/").send(&service).await;
+        assert_eq!(res.status_code().unwrap(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_normalize_always() {
+        let router =
+            Router::with_hoop(NormalizePath::new(TrailingSlash::Always)).push(Router::with_path("a/b/").get(hello_world));
+        let service = Service::new(router);
+        let res = TestClient::get("http://127.0.0.1:7878/a//b").send(&service).await;
+        assert_eq!(res.status_code().unwrap(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_normalize_with_redirects() {
+        let router = Router::with_hoop(NormalizePath::new(TrailingSlash::Trim).with_redirects(StatusCode::MOVED_PERMANENTLY))
+            .push(Router::with_path("a/b").get(hello_world));
+        let service = Service::new(router);
+        let res = TestClient::get("http://127.0.0.1:7878/a//b/").send(&service).await;
+        assert_eq!(res.status_code().unwrap(), StatusCode::MOVED_PERMANENTLY);
+    }
+
+    #[test]
+    fn test_clean_path() {
+        assert_eq!(clean_path("/files/../../etc/passwd"), "/etc/passwd");
+        assert_eq!(clean_path("/a/./b"), "/a/b");
+        assert_eq!(clean_path("/a/b/"), "/a/b/");
+        assert_eq!(clean_path("/../"), "/");
+        assert_eq!(clean_path("/"), "/");
+    }
+
+    #[handler]
+    async fn echo_uri_path(req: &mut Request) -> String {
+        req.uri().path().to_owned()
+    }
+
+    #[handler]
+    async fn echo_path_param(req: &mut Request) -> String {
+        req.param::<String>("path").unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_normalize_clean_path_rewrites_uri() {
+        let router = Router::with_hoop(NormalizePath::new(TrailingSlash::Trim).with_clean_path(true))
+            .push(Router::with_path("files/<*path>").get(echo_uri_path));
+        let service = Service::new(router);
+        let res = TestClient::get("http://127.0.0.1:7878/files/../../etc/passwd")
+            .send(&service)
+            .await;
+        assert_eq!(res.take_string().await.unwrap(), "/etc/passwd");
+    }
+
+    /// Route matching (and wildcard parameter capture) happens against the original `Uri`
+    /// before this hoop runs, so the `<*path>` capture itself is not retroactively sanitized —
+    /// see the doc comment on [`NormalizePath::with_clean_path`].
+    #[tokio::test]
+    async fn test_normalize_clean_path_does_not_sanitize_wildcard_params() {
+        let router = Router::with_hoop(NormalizePath::new(TrailingSlash::Trim).with_clean_path(true))
+            .push(Router::with_path("files/<*path>").get(echo_path_param));
+        let service = Service::new(router);
+        let res = TestClient::get("http://127.0.0.1:7878/files/../../etc/passwd")
+            .send(&service)
+            .await;
+        assert_eq!(res.take_string().await.unwrap(), "../../etc/passwd");
+    }
+
+    #[tokio::test]
+    async fn test_clean_path_param_sanitizes_wildcard_capture() {
+        let router = Router::new().push(Router::with_path("files/<*path>").goal(clean_path_param("path", echo_path_param)));
+        let service = Service::new(router);
+        let res = TestClient::get("http://127.0.0.1:7878/files/../../etc/passwd")
+            .send(&service)
+            .await;
+        assert_eq!(res.take_string().await.unwrap(), "etc/passwd");
+    }
+}
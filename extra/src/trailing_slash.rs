@@ -44,6 +44,8 @@ pub struct TrailingSlash {
     pub filter: Option<FilterFn>,
     /// Redirect code is used when redirect url.
     pub redirect_code: StatusCode,
+    /// Whether to redirect the client, or rewrite `req.uri()` in place and fall through to routing.
+    pub redirect: bool,
 }
 impl TrailingSlash {
     /// Create new `TrailingSlash`.
@@ -53,6 +55,7 @@ impl TrailingSlash {
             action,
             filter: None,
             redirect_code: StatusCode::MOVED_PERMANENTLY,
+            redirect: true,
         }
     }
     /// Create new `TrailingSlash` and sets it's action as [`TrailingSlashAction::Add`].
@@ -62,6 +65,7 @@ impl TrailingSlash {
             action: TrailingSlashAction::Add,
             filter: None,
             redirect_code: StatusCode::MOVED_PERMANENTLY,
+            redirect: true,
         }
     }
     /// Create new `TrailingSlash` and sets it's action as [`TrailingSlashAction::Remove`].
@@ -71,6 +75,7 @@ impl TrailingSlash {
             action: TrailingSlashAction::Remove,
             filter: None,
             redirect_code: StatusCode::MOVED_PERMANENTLY,
+            redirect: true,
         }
     }
     /// Set filter and returns new `TrailingSlash`.
@@ -87,6 +92,18 @@ impl TrailingSlash {
     pub fn with_redirect_code(self, redirect_code: StatusCode) -> Self {
         Self { redirect_code, ..self }
     }
+
+    /// Set whether the client is redirected (`true`, the default) or the request's `Uri` is
+    /// rewritten in place (`false`), with no `skip_rest()` and no redirect response.
+    ///
+    /// Route matching already happened against the original `Uri` by the time this hoop runs,
+    /// so disabling the redirect doesn't change which route was selected. What it does is
+    /// avoid the extra client round-trip and present a canonical `req.uri()` to whatever runs
+    /// after this hoop (further hoops, the matched handler, logging).
+    #[inline]
+    pub fn with_redirect(self, redirect: bool) -> Self {
+        Self { redirect, ..self }
+    }
 }
 
 #[async_trait]
@@ -108,15 +125,19 @@ impl Handler for TrailingSlash {
                 None
             };
             if let Some(new_uri) = new_uri {
-                ctrl.skip_rest();
-                res.set_body(Body::None);
-                match Redirect::with_status_code(self.redirect_code, new_uri) {
-                    Ok(redirect) => {
-                        res.render(redirect);
-                    }
-                    Err(e) => {
-                        tracing::error!(error = ?e, "redirect failed");
+                if self.redirect {
+                    ctrl.skip_rest();
+                    res.set_body(Body::None);
+                    match Redirect::with_status_code(self.redirect_code, new_uri) {
+                        Ok(redirect) => {
+                            res.render(redirect);
+                        }
+                        Err(e) => {
+                            tracing::error!(error = ?e, "redirect failed");
+                        }
                     }
+                } else {
+                    *req.uri_mut() = new_uri;
                 }
             }
         }
@@ -124,7 +145,7 @@ impl Handler for TrailingSlash {
 }
 
 #[inline]
-fn replace_uri_path(original_uri: &Uri, new_path: &str) -> Uri {
+pub(crate) fn replace_uri_path(original_uri: &Uri, new_path: &str) -> Uri {
     let mut uri_parts = original_uri.clone().into_parts();
     let path = match original_uri.query() {
         Some(query) => Cow::from(format!("{}?{}", new_path, query)),
@@ -194,4 +215,26 @@ mod tests {
             .await;
         assert_eq!(res.status_code().unwrap(), StatusCode::OK);
     }
+    #[tokio::test]
+    async fn test_remove_slash_without_redirect() {
+        let router =
+            Router::with_hoop(remove_slash().with_redirect(false)).push(Router::with_path("hello").get(hello_world));
+        let service = Service::new(router);
+        let res = TestClient::get("http://127.0.0.1:7878/hello/").send(&service).await;
+        assert_eq!(res.status_code().unwrap(), StatusCode::OK);
+    }
+
+    #[handler]
+    async fn echo_uri_path(req: &mut Request) -> String {
+        req.uri().path().to_owned()
+    }
+
+    #[tokio::test]
+    async fn test_remove_slash_without_redirect_rewrites_uri() {
+        let router = Router::with_hoop(remove_slash().with_redirect(false))
+            .push(Router::with_path("hello").get(echo_uri_path));
+        let service = Service::new(router);
+        let res = TestClient::get("http://127.0.0.1:7878/hello/").send(&service).await;
+        assert_eq!(res.take_string().await.unwrap(), "/hello");
+    }
 }
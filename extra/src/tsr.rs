@@ -0,0 +1,194 @@
+//! Trailing-slash normalization derived from the route table.
+//!
+//! Unlike [`crate::add_slash`]/[`crate::remove_slash`], which hoop every incoming request
+//! regardless of whether a route exists for it, [`tsr`] only ever redirects a path that is
+//! actually registered: it installs the opposite-slash sibling for a route and redirects that
+//! sibling to the canonical path, carrying through both the matched path parameters and the
+//! original query string.
+
+use salvo_core::prelude::*;
+
+use crate::trailing_slash::replace_uri_path;
+
+/// Error returned when trailing-slash handling is requested for a path that has no
+/// opposite-slash sibling.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TsrError {
+    /// The root path `/` was given; it has no opposite-slash variant to redirect from.
+    RootPath,
+}
+
+impl std::fmt::Display for TsrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TsrError::RootPath => write!(f, "cannot install trailing-slash redirects for the root path `/`"),
+        }
+    }
+}
+
+impl std::error::Error for TsrError {}
+
+/// Given the canonical `path` a route is registered under, return `(canonical, opposite)` where
+/// `opposite` is the same path with its trailing slash toggled.
+///
+/// `path` is returned unchanged as `canonical` — whichever trailing-slash form the caller
+/// registered is the one kept as canonical, e.g. `tsr_paths("files/")` keeps `files/` canonical
+/// and produces `files` as the opposite, matching how `Static` routes are usually mounted.
+///
+/// Returns [`TsrError::RootPath`] for `/`, which has no opposite-slash sibling.
+pub fn tsr_paths(path: &str) -> Result<(String, String), TsrError> {
+    let trimmed = path.trim_end_matches('/');
+    if trimmed.is_empty() {
+        return Err(TsrError::RootPath);
+    }
+    let opposite = if path.ends_with('/') {
+        trimmed.to_owned()
+    } else {
+        format!("{trimmed}/")
+    };
+    Ok((path.to_owned(), opposite))
+}
+
+/// Rebuild `template` (a route path pattern like `users/<id>` or `users/<id:num>`) by
+/// substituting each `<name>`/`<*name>`/`<name:pattern>` placeholder with the matching value
+/// out of `params`, so the canonical URL can be reconstructed from a request that matched the
+/// opposite-slash sibling route.
+pub fn render_path_params(template: &str, params: &PathParams) -> String {
+    template
+        .split('/')
+        .map(|segment| {
+            if !segment.starts_with('<') {
+                return segment.to_owned();
+            }
+            let name = segment
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .trim_start_matches('*');
+            let name = name.split(':').next().unwrap_or(name);
+            params.get(name).map(String::as_str).unwrap_or(segment).to_owned()
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Handler installed on the opposite-slash sibling route: redirects to `canonical_pattern`
+/// rendered against the request's matched path parameters, preserving the query string.
+struct TsrRedirect {
+    canonical_pattern: String,
+}
+
+#[async_trait]
+impl Handler for TsrRedirect {
+    async fn handle(&self, req: &mut Request, _depot: &mut Depot, res: &mut Response, ctrl: &mut FlowCtrl) {
+        let mut target_path = render_path_params(&self.canonical_pattern, req.params());
+        if !target_path.starts_with('/') {
+            target_path.insert(0, '/');
+        }
+        let new_uri = replace_uri_path(req.uri(), &target_path);
+        ctrl.skip_rest();
+        match Redirect::with_status_code(StatusCode::MOVED_PERMANENTLY, new_uri) {
+            Ok(redirect) => res.render(redirect),
+            Err(e) => tracing::error!(error = ?e, "tsr redirect failed"),
+        }
+    }
+}
+
+/// Register `handler` at `path`, and automatically install a sibling route for the
+/// opposite trailing-slash form that redirects to the canonical `path`, preserving path
+/// parameters and the query string.
+///
+/// `path` is served exactly as given — its own trailing slash, or lack of one, is the canonical
+/// form (see [`tsr_paths`]); the sibling route is registered for `path` with the trailing slash
+/// toggled. Because
+/// the sibling only exists for routes actually pushed through `tsr`, it won't redirect paths
+/// that have no matching route at all, unlike a blanket [`crate::add_slash`]/[`crate::remove_slash`]
+/// hoop.
+///
+/// Returns [`TsrError::RootPath`] if `path` is `/`, which has no opposite-slash sibling.
+pub fn tsr(path: impl Into<String>, handler: impl Handler) -> Result<Router, TsrError> {
+    let path = path.into();
+    let (canonical, opposite) = tsr_paths(&path)?;
+    // Path matching ignores a trailing slash, so the canonical route only accepts requests whose
+    // slash form matches its own; the other form falls through to the redirecting sibling.
+    let canonical_ends_with_slash = canonical.ends_with('/');
+    Ok(Router::new()
+        .push(
+            Router::with_path(&canonical)
+                .filter_fn(move |req, _| req.uri().path().ends_with('/') == canonical_ends_with_slash)
+                .goal(handler),
+        )
+        .push(Router::with_path(&opposite).goal(TsrRedirect {
+            canonical_pattern: canonical,
+        })))
+}
+
+#[cfg(test)]
+mod tests {
+    use salvo_core::http::StatusCode;
+    use salvo_core::prelude::*;
+    use salvo_core::test::TestClient;
+
+    use super::*;
+
+    #[test]
+    fn test_tsr_paths() {
+        assert_eq!(tsr_paths("users").unwrap(), ("users".into(), "users/".into()));
+        assert_eq!(tsr_paths("users/").unwrap(), ("users/".into(), "users".into()));
+        assert_eq!(tsr_paths("/").unwrap_err(), TsrError::RootPath);
+    }
+
+    #[test]
+    fn test_render_path_params() {
+        let mut params = PathParams::new();
+        params.insert("id".into(), "42".into());
+        assert_eq!(render_path_params("users/<id>", &params), "users/42");
+    }
+
+    #[test]
+    fn test_render_path_params_constrained() {
+        let mut params = PathParams::new();
+        params.insert("id".into(), "42".into());
+        assert_eq!(render_path_params("users/<id:num>", &params), "users/42");
+    }
+
+    #[handler]
+    async fn get_user(req: &mut Request) -> String {
+        req.param::<String>("id").unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_tsr_redirects_opposite_slash() {
+        let router = Router::new().push(tsr("users/<id>", get_user).unwrap());
+        let service = Service::new(router);
+
+        let res = TestClient::get("http://127.0.0.1:7878/users/42").send(&service).await;
+        assert_eq!(res.status_code().unwrap(), StatusCode::OK);
+
+        let res = TestClient::get("http://127.0.0.1:7878/users/42/")
+            .send(&service)
+            .await;
+        assert_eq!(res.status_code().unwrap(), StatusCode::MOVED_PERMANENTLY);
+    }
+
+    #[test]
+    fn test_tsr_rejects_root() {
+        assert_eq!(tsr("/", get_user).unwrap_err(), TsrError::RootPath);
+    }
+
+    #[handler]
+    async fn list_files() -> &'static str {
+        "file listing"
+    }
+
+    #[tokio::test]
+    async fn test_tsr_preserves_trailing_slash_as_canonical() {
+        let router = Router::new().push(tsr("files/", list_files).unwrap());
+        let service = Service::new(router);
+
+        let res = TestClient::get("http://127.0.0.1:7878/files/").send(&service).await;
+        assert_eq!(res.status_code().unwrap(), StatusCode::OK);
+
+        let res = TestClient::get("http://127.0.0.1:7878/files").send(&service).await;
+        assert_eq!(res.status_code().unwrap(), StatusCode::MOVED_PERMANENTLY);
+    }
+}